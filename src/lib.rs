@@ -24,6 +24,64 @@ pub enum LooseTime {
     Rfc3339(String),
 }
 
+//
+// ---------- Validated-newtype framework ----------
+//
+
+/// A value object: a newtype that can only be constructed from a raw backing
+/// value that passed validation, and that hands the backing value back out.
+///
+/// Implemented by hand for the richer wrappers ([`Email`], [`Age`]) and generated
+/// by [`value_object!`] for the plain string-backed identifiers ([`Username`],
+/// [`Handle`]).
+pub trait ValueObject: Sized {
+    type Raw;
+    fn validate(raw: Self::Raw) -> Result<Self, AclError>;
+    fn as_raw(&self) -> &Self::Raw;
+}
+
+/// Declare a string-backed validated newtype, generating the struct, its
+/// `new`/`parse` constructors, `as_str`/`get` accessors, validating `Serialize`/
+/// `Deserialize`, and the [`ValueObject`] impl. The validator receives the raw
+/// `&str` and returns `Ok(())` or an [`AclError`].
+macro_rules! value_object {
+    ($(#[$meta:meta])* $name:ident, $validate:expr $(,)?) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+        pub struct $name(String);
+
+        impl $name {
+            pub fn new(raw: impl Into<String>) -> Result<Self, AclError> {
+                let raw = raw.into();
+                let validate: fn(&str) -> Result<(), AclError> = $validate;
+                validate(&raw)?;
+                Ok(Self(raw))
+            }
+            pub fn parse(raw: &str) -> Result<Self, AclError> { Self::new(raw) }
+            pub fn as_str(&self) -> &str { &self.0 }
+            pub fn get(&self) -> &String { &self.0 }
+        }
+
+        impl ValueObject for $name {
+            type Raw = String;
+            fn validate(raw: Self::Raw) -> Result<Self, AclError> { Self::new(raw) }
+            fn as_raw(&self) -> &Self::Raw { &self.0 }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+                self.0.serialize(s)
+            }
+        }
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+                let raw = String::deserialize(d)?;
+                Self::new(raw).map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
 //
 // ---------- Domain (trusted) ----------
 //
@@ -36,8 +94,24 @@ pub struct User {
     pub created_at: OffsetDateTime,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Username(String);
+value_object!(
+    /// A non-empty username.
+    Username,
+    |s: &str| if s.trim().is_empty() { Err(AclError::UsernameEmpty) } else { Ok(()) }
+);
+
+value_object!(
+    /// A short, URL-safe handle: 3–32 characters of ASCII alphanumerics plus `_`/`-`.
+    Handle,
+    |s: &str| {
+        let len = s.chars().count();
+        if !(3..=32).contains(&len) { return Err(AclError::InvalidHandle); }
+        if !s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+            return Err(AclError::InvalidHandle);
+        }
+        Ok(())
+    }
+);
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Email(EmailAddress);
@@ -45,20 +119,125 @@ pub struct Email(EmailAddress);
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Age(NonZeroU8);
 
-impl Username {
-    pub fn new(s: impl Into<String>) -> Result<Self, AclError> {
-        let s = s.into();
-        if s.trim().is_empty() { return Err(AclError::UsernameEmpty); }
-        Ok(Self(s))
-    }
-    pub fn as_str(&self) -> &str { &self.0 }
+impl ValueObject for Email {
+    type Raw = EmailAddress;
+    fn validate(raw: Self::Raw) -> Result<Self, AclError> { Ok(Self(raw)) }
+    fn as_raw(&self) -> &Self::Raw { &self.0 }
+}
+
+impl ValueObject for Age {
+    type Raw = NonZeroU8;
+    fn validate(raw: Self::Raw) -> Result<Self, AclError> { Ok(Self(raw)) }
+    fn as_raw(&self) -> &Self::Raw { &self.0 }
 }
 
 impl Email {
     pub fn parse(s: &str) -> Result<Self, AclError> {
-        EmailAddress::parse(s).map(Self).map_err(|_| AclError::InvalidEmail)
+        // Normalize the domain to its IDNA/punycode ASCII form before validation so
+        // that two encodings of the same internationalized domain compare equal and
+        // `Ord`/`Eq` on `Email` are canonical. IP-literal domains (`[127.0.0.1]`,
+        // `[IPv6:...]`) are not DNS names, so they skip IDNA and pass through verbatim.
+        let (local, domain) = s.rsplit_once('@').ok_or(AclError::InvalidEmail)?;
+        let normalized = if domain.starts_with('[') {
+            s.to_owned()
+        } else {
+            let ascii_domain = idna::domain_to_ascii(domain).map_err(|_| AclError::InvalidDomain)?;
+            format!("{local}@{ascii_domain}")
+        };
+        EmailAddress::parse(&normalized).map(Self).map_err(|_| AclError::InvalidEmail)
     }
     pub fn as_str(&self) -> &str { self.0.as_str() }
+
+    /// The domain mapped back to its Unicode form for display. Returns the ASCII
+    /// domain unchanged when it carries no punycode labels.
+    pub fn domain_unicode(&self) -> String {
+        let domain = self.0.domain();
+        idna::domain_to_unicode(domain).0
+    }
+
+    /// Parse an RFC 5322 `mailbox`: an optional display-name phrase followed by an
+    /// addr-spec, the latter wrapped in `<...>` when a phrase is present. Handles
+    /// quoted-string display names (with `\"`/`\\` escapes), bare atom phrases, and
+    /// parenthesized comments, which are stripped. A single mailbox only — a comma
+    /// in the input is rejected.
+    pub fn parse_mailbox(s: &str) -> Result<Mailbox, AclError> {
+        let s = strip_comments(s);
+        let s = s.trim();
+        if s.is_empty() { return Err(AclError::InvalidMailbox); }
+        if s.contains(',') { return Err(AclError::InvalidMailbox); }
+
+        // name-addr with angle-addr: [phrase] "<" addr-spec ">"
+        if let Some(lt) = s.rfind('<') {
+            let gt = s.rfind('>').ok_or(AclError::InvalidMailbox)?;
+            if gt < lt { return Err(AclError::InvalidMailbox); }
+            let addr = s[lt + 1..gt].trim();
+            let phrase = s[..lt].trim();
+            let display_name = if phrase.is_empty() {
+                None
+            } else {
+                Some(parse_phrase(phrase)?)
+            };
+            let email = Email::parse(addr)?;
+            return Ok(Mailbox { display_name, email });
+        }
+
+        // bare addr-spec, no display name
+        Ok(Mailbox { display_name: None, email: Email::parse(s)? })
+    }
+}
+
+/// A single RFC 5322 mailbox: an address plus an optional display name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mailbox {
+    pub display_name: Option<String>,
+    pub email: Email,
+}
+
+/// Remove RFC 5322 parenthesized comments, honouring `\(`/`\)` escapes and nested
+/// comments. Parentheses inside a quoted-string are literal, so quoted regions are
+/// copied through verbatim rather than treated as comment delimiters.
+fn strip_comments(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut depth = 0usize;
+    let mut in_quotes = false;
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if in_quotes => {
+                // Inside quotes, copy the escape pair through untouched.
+                out.push(c);
+                if let Some(n) = chars.next() { out.push(n); }
+            }
+            '"' if depth == 0 => { in_quotes = !in_quotes; out.push(c); }
+            _ if in_quotes => out.push(c),
+            '\\' if depth > 0 => { chars.next(); }
+            '(' => depth += 1,
+            ')' if depth > 0 => depth -= 1,
+            _ if depth == 0 => out.push(c),
+            _ => {}
+        }
+    }
+    out
+}
+
+/// Interpret a display-name phrase: either a single quoted-string (unescaped) or a
+/// sequence of atoms collapsed to single spaces.
+fn parse_phrase(phrase: &str) -> Result<String, AclError> {
+    let phrase = phrase.trim();
+    if let Some(rest) = phrase.strip_prefix('"') {
+        let inner = rest.strip_suffix('"').ok_or(AclError::InvalidMailbox)?;
+        let mut out = String::with_capacity(inner.len());
+        let mut chars = inner.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => out.push(chars.next().ok_or(AclError::InvalidMailbox)?),
+                _ => out.push(c),
+            }
+        }
+        Ok(out)
+    } else {
+        Ok(phrase.split_whitespace().collect::<Vec<_>>().join(" "))
+    }
 }
 
 impl Age {
@@ -71,6 +250,157 @@ impl Age {
     pub fn get(self) -> u8 { self.0.get() }
 }
 
+//
+// ---------- mailto: URIs ----------
+//
+
+/// What to do when a `mailto:` query carries a header we don't model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnknownHeaderPolicy {
+    /// Silently drop unrecognized headers.
+    Ignore,
+    /// Fail with [`AclError::InvalidMailto`] on the first unrecognized header.
+    Reject,
+}
+
+impl Default for UnknownHeaderPolicy {
+    fn default() -> Self { UnknownHeaderPolicy::Ignore }
+}
+
+/// A parsed `mailto:` URI. Recipients and `cc`/`bcc` addresses reuse the domain
+/// email validation, so every address in the URI is a real [`Email`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Mailto {
+    pub to: Vec<Email>,
+    pub cc: Vec<Email>,
+    pub bcc: Vec<Email>,
+    pub subject: Option<String>,
+    pub body: Option<String>,
+}
+
+impl Mailto {
+    /// Parse a `mailto:` URI with the default (ignore-unknown) header policy.
+    pub fn parse(s: &str) -> Result<Mailto, AclError> {
+        Self::parse_with(s, UnknownHeaderPolicy::default())
+    }
+
+    /// Parse a `mailto:` URI, controlling how unknown headers are handled.
+    pub fn parse_with(s: &str, policy: UnknownHeaderPolicy) -> Result<Mailto, AclError> {
+        let rest = s.strip_prefix("mailto:").ok_or(AclError::InvalidMailto)?;
+        let (path, query) = match rest.split_once('?') {
+            Some((p, q)) => (p, Some(q)),
+            None => (rest, None),
+        };
+
+        let mut out = Mailto::default();
+        for addr in path.split(',').filter(|a| !a.is_empty()) {
+            out.to.push(Email::parse(&percent_decode(addr)?)?);
+        }
+
+        if let Some(query) = query {
+            for pair in query.split('&').filter(|p| !p.is_empty()) {
+                let (key, value) = pair.split_once('=').ok_or(AclError::InvalidMailto)?;
+                let value = percent_decode(value)?;
+                match key {
+                    "to" => {
+                        for addr in value.split(',').filter(|a| !a.is_empty()) {
+                            out.to.push(Email::parse(addr)?);
+                        }
+                    }
+                    "cc" => {
+                        for addr in value.split(',').filter(|a| !a.is_empty()) {
+                            out.cc.push(Email::parse(addr)?);
+                        }
+                    }
+                    "bcc" => {
+                        for addr in value.split(',').filter(|a| !a.is_empty()) {
+                            out.bcc.push(Email::parse(addr)?);
+                        }
+                    }
+                    "subject" => out.subject = Some(value),
+                    "body" => out.body = Some(value),
+                    _ => {
+                        if policy == UnknownHeaderPolicy::Reject {
+                            return Err(AclError::InvalidMailto);
+                        }
+                    }
+                }
+            }
+        }
+
+        if out.to.is_empty() {
+            return Err(AclError::InvalidMailto);
+        }
+        Ok(out)
+    }
+
+    /// Render back to a `mailto:` URI, percent-encoding reserved characters. The
+    /// recipient list goes in the path; everything else becomes query headers.
+    pub fn to_uri(&self) -> String {
+        let mut uri = String::from("mailto:");
+        let to: Vec<String> = self.to.iter().map(|e| percent_encode(e.as_str())).collect();
+        uri.push_str(&to.join(","));
+
+        let mut params: Vec<String> = Vec::new();
+        let mut push_addrs = |key: &str, addrs: &[Email]| {
+            if !addrs.is_empty() {
+                let joined = addrs.iter().map(|e| percent_encode(e.as_str())).collect::<Vec<_>>().join(",");
+                params.push(format!("{key}={joined}"));
+            }
+        };
+        push_addrs("cc", &self.cc);
+        push_addrs("bcc", &self.bcc);
+        if let Some(subject) = &self.subject {
+            params.push(format!("subject={}", percent_encode(subject)));
+        }
+        if let Some(body) = &self.body {
+            params.push(format!("body={}", percent_encode(body)));
+        }
+        if !params.is_empty() {
+            uri.push('?');
+            uri.push_str(&params.join("&"));
+        }
+        uri
+    }
+}
+
+/// Percent-decode a URI component, mapping malformed escapes to an error.
+fn percent_decode(s: &str) -> Result<String, AclError> {
+    let mut out = Vec::with_capacity(s.len());
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' => {
+                let hex = s.get(i + 1..i + 3).ok_or(AclError::InvalidMailto)?;
+                if !hex.bytes().all(|h| h.is_ascii_hexdigit()) {
+                    return Err(AclError::InvalidMailto);
+                }
+                let byte = u8::from_str_radix(hex, 16).map_err(|_| AclError::InvalidMailto)?;
+                out.push(byte);
+                i += 3;
+            }
+            // RFC 6068: in `mailto:`, `+` is a literal — only `%20` is a space.
+            b => { out.push(b); i += 1; }
+        }
+    }
+    String::from_utf8(out).map_err(|_| AclError::InvalidMailto)
+}
+
+/// Percent-encode everything outside the RFC 3986 unreserved set.
+fn percent_encode(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for &b in s.as_bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(b as char);
+            }
+            _ => out.push_str(&format!("%{b:02X}")),
+        }
+    }
+    out
+}
+
 //
 // ---------- ACL Errors ----------
 //
@@ -83,10 +413,30 @@ pub enum AclError {
     UsernameEmpty,
     #[error("invalid email")]
     InvalidEmail,
+    #[error("invalid domain")]
+    InvalidDomain,
+    #[error("invalid mailbox")]
+    InvalidMailbox,
+    #[error("invalid mailto uri")]
+    InvalidMailto,
     #[error("invalid age")]
     InvalidAge,
+    #[error("invalid handle")]
+    InvalidHandle,
     #[error("invalid created_at")]
     InvalidCreatedAt,
+    #[error("{field}: {source}")]
+    Field {
+        field: &'static str,
+        source: Box<AclError>,
+    },
+}
+
+impl AclError {
+    /// Tag an error with the DTO field it came from, for the validation harvest.
+    fn field(name: &'static str, source: AclError) -> Self {
+        AclError::Field { field: name, source: Box::new(source) }
+    }
 }
 
 //
@@ -110,6 +460,57 @@ impl Acl {
         Ok(User { username, age, email, created_at })
     }
 
+    /// Like [`Acl::to_domain`], but runs every field validator independently and
+    /// collects *all* failures instead of bailing on the first one. Useful at API
+    /// boundaries where the caller wants the whole list of bad fields at once.
+    pub fn to_domain_all(dto: UserDto) -> Result<User, Vec<AclError>> {
+        let mut errors = Vec::new();
+
+        let username = match dto
+            .user_name
+            .ok_or(AclError::Missing("user_name"))
+            .and_then(Username::new)
+        {
+            Ok(v) => Some(v),
+            Err(e) => { errors.push(AclError::field("user_name", e)); None }
+        };
+        let age = match dto
+            .user_age
+            .ok_or(AclError::Missing("user_age"))
+            .and_then(|s| Age::parse_str(&s))
+        {
+            Ok(v) => Some(v),
+            Err(e) => { errors.push(AclError::field("user_age", e)); None }
+        };
+        let email = match dto
+            .email_address
+            .ok_or(AclError::Missing("email_address"))
+            .and_then(|s| Email::parse(&s))
+        {
+            Ok(v) => Some(v),
+            Err(e) => { errors.push(AclError::field("email_address", e)); None }
+        };
+        let created_at = match dto
+            .created_at
+            .ok_or(AclError::Missing("created_at"))
+            .and_then(parse_loose_time)
+        {
+            Ok(v) => Some(v),
+            Err(e) => { errors.push(AclError::field("created_at", e)); None }
+        };
+
+        if errors.is_empty() {
+            Ok(User {
+                username: username.unwrap(),
+                age: age.unwrap(),
+                email: email.unwrap(),
+                created_at: created_at.unwrap(),
+            })
+        } else {
+            Err(errors)
+        }
+    }
+
     pub fn to_dto(user: &User) -> UserDto {
         UserDto {
             user_name: Some(user.username.as_str().to_owned()),
@@ -120,19 +521,91 @@ impl Acl {
     }
 }
 
-fn parse_loose_time(t: LooseTime) -> Result<OffsetDateTime, AclError> {
-    match t {
-        LooseTime::UnixSecs(s) => OffsetDateTime::from_unix_timestamp(s).map_err(|_| AclError::InvalidCreatedAt),
-        LooseTime::Rfc3339(s) => {
-            // try strict RFC3339; fall back to a common format if the API lies
-            let rfc = time::format_description::well_known::Rfc3339;
-            if let Ok(dt) = OffsetDateTime::parse(&s, &rfc) { return Ok(dt); }
-            let alt = format_description!("[year]-[month]-[day] [hour]:[minute]:[second] UTC");
-            OffsetDateTime::parse(&s, &alt).map_err(|_| AclError::InvalidCreatedAt)
+/// A pluggable parser for the "random format" timestamps the DTO admits to.
+///
+/// Integer timestamps are classified by magnitude into seconds/millis/micros and
+/// scaled to nanoseconds before [`OffsetDateTime::from_unix_timestamp_nanos`].
+/// String timestamps are
+/// tried against RFC3339, then RFC2822, then each entry of `string_formats` in
+/// order — the first format that parses wins. Callers can extend `string_formats`
+/// or retune the magnitude thresholds.
+pub struct LooseTimeParser {
+    /// Ordered list of `time` format descriptions tried after the well-known ones.
+    pub string_formats: Vec<&'static [time::format_description::FormatItem<'static>]>,
+    /// Integer values `>=` this are treated as milliseconds.
+    pub millis_threshold: i64,
+    /// Integer values `>=` this are treated as microseconds.
+    pub micros_threshold: i64,
+}
+
+impl Default for LooseTimeParser {
+    fn default() -> Self {
+        // Date-only inputs deterministically assume midnight UTC.
+        LooseTimeParser {
+            string_formats: vec![
+                format_description!("[year]-[month]-[day] [hour]:[minute]:[second] UTC"),
+                format_description!("[year]-[month]-[day] [hour]:[minute]:[second]"),
+                format_description!(
+                    "[year]-[month]-[day] [hour]:[minute]:[second] [offset_hour][offset_minute]"
+                ),
+                format_description!(
+                    "[year]-[month]-[day]T[hour]:[minute]:[second][offset_hour][offset_minute]"
+                ),
+                format_description!("[year]-[month]-[day]"),
+            ],
+            millis_threshold: 1_000_000_000_000,     // 1e12
+            micros_threshold: 1_000_000_000_000_000, // 1e15
         }
     }
 }
 
+impl LooseTimeParser {
+    pub fn parse(&self, t: LooseTime) -> Result<OffsetDateTime, AclError> {
+        match t {
+            LooseTime::UnixSecs(v) => self.parse_int(v),
+            LooseTime::Rfc3339(s) => self.parse_str(&s),
+        }
+    }
+
+    /// Classify an integer timestamp by magnitude and scale to nanoseconds.
+    fn parse_int(&self, v: i64) -> Result<OffsetDateTime, AclError> {
+        let abs = v.unsigned_abs();
+        let nanos: i128 = if abs >= self.micros_threshold as u64 {
+            v as i128 * 1_000
+        } else if abs >= self.millis_threshold as u64 {
+            v as i128 * 1_000_000
+        } else {
+            v as i128 * 1_000_000_000
+        };
+        OffsetDateTime::from_unix_timestamp_nanos(nanos).map_err(|_| AclError::InvalidCreatedAt)
+    }
+
+    fn parse_str(&self, s: &str) -> Result<OffsetDateTime, AclError> {
+        use time::format_description::well_known::{Rfc2822, Rfc3339};
+
+        if let Ok(dt) = OffsetDateTime::parse(s, &Rfc3339) { return Ok(dt); }
+        if let Ok(dt) = OffsetDateTime::parse(s, &Rfc2822) { return Ok(dt); }
+
+        for fmt in &self.string_formats {
+            // Formats that carry an offset parse straight to an `OffsetDateTime`.
+            if let Ok(dt) = OffsetDateTime::parse(s, fmt) { return Ok(dt); }
+            // Zone-less datetime formats parse as a `PrimitiveDateTime`; assume UTC.
+            if let Ok(pdt) = time::PrimitiveDateTime::parse(s, fmt) {
+                return Ok(pdt.assume_utc());
+            }
+            // A date-only format yields a `Date`; assume midnight UTC for it.
+            if let Ok(date) = time::Date::parse(s, fmt) {
+                return Ok(date.midnight().assume_utc());
+            }
+        }
+        Err(AclError::InvalidCreatedAt)
+    }
+}
+
+fn parse_loose_time(t: LooseTime) -> Result<OffsetDateTime, AclError> {
+    LooseTimeParser::default().parse(t)
+}
+
 //
 // ---------- Discriminated union example for “states” ----------
 //
@@ -215,6 +688,35 @@ mod tests {
         assert!(matches!(Acl::to_domain(dto), Err(AclError::InvalidEmail)));
     }
 
+    #[test]
+    fn dto_to_domain_all_collects_every_failure() {
+        let dto = UserDto {
+            user_name: Some("   ".into()),
+            user_age: Some("nope".into()),
+            email_address: Some("not-an-email".into()),
+            created_at: None,
+        };
+        let errs = Acl::to_domain_all(dto).unwrap_err();
+        assert_eq!(errs.len(), 4);
+        let rendered: Vec<String> = errs.iter().map(|e| e.to_string()).collect();
+        assert!(rendered.iter().any(|e| e.starts_with("user_name:")));
+        assert!(rendered.iter().any(|e| e.starts_with("user_age:")));
+        assert!(rendered.iter().any(|e| e.starts_with("email_address:")));
+        assert!(rendered.iter().any(|e| e.starts_with("created_at:")));
+    }
+
+    #[test]
+    fn dto_to_domain_all_success() {
+        let dto = UserDto {
+            user_name: Some("sigma".into()),
+            user_age: Some("42".into()),
+            email_address: Some("sigma@example.com".into()),
+            created_at: Some(LooseTime::UnixSecs(1_700_000_000)),
+        };
+        let u = Acl::to_domain_all(dto).unwrap();
+        assert_eq!(u.username.as_str(), "sigma");
+    }
+
     #[test]
     fn roundtrip_domain_to_dto() {
         let user = User {
@@ -230,6 +732,171 @@ mod tests {
         assert_eq!(dto.created_at, Some(LooseTime::UnixSecs(1234567890)));
     }
 
+    #[test]
+    fn email_idna_normalizes_domain() {
+        let unicode = Email::parse("user@münchen.de").unwrap();
+        let ascii = Email::parse("user@xn--mnchen-3ya.de").unwrap();
+        assert_eq!(unicode, ascii);
+        assert_eq!(unicode.as_str(), "user@xn--mnchen-3ya.de");
+        assert_eq!(unicode.domain_unicode(), "münchen.de");
+    }
+
+    #[test]
+    fn email_ip_literal_domain_skips_idna() {
+        let e = Email::parse("user@[127.0.0.1]").unwrap();
+        assert_eq!(e.as_str(), "user@[127.0.0.1]");
+    }
+
+    #[test]
+    fn mailbox_quoted_display_name() {
+        let m = Email::parse_mailbox("\"Jane Doe\" <jane@example.com>").unwrap();
+        assert_eq!(m.display_name.as_deref(), Some("Jane Doe"));
+        assert_eq!(m.email.as_str(), "jane@example.com");
+    }
+
+    #[test]
+    fn mailbox_atom_phrase_and_comment() {
+        let m = Email::parse_mailbox("Jane Doe (the boss) <jane@example.com>").unwrap();
+        assert_eq!(m.display_name.as_deref(), Some("Jane Doe"));
+        assert_eq!(m.email.as_str(), "jane@example.com");
+    }
+
+    #[test]
+    fn mailbox_parens_inside_quotes_are_literal() {
+        let m = Email::parse_mailbox("\"Jane (the boss)\" <jane@example.com>").unwrap();
+        assert_eq!(m.display_name.as_deref(), Some("Jane (the boss)"));
+        assert_eq!(m.email.as_str(), "jane@example.com");
+    }
+
+    #[test]
+    fn mailbox_escaped_quote() {
+        let m = Email::parse_mailbox("\"Jane \\\"JD\\\" Doe\" <jane@example.com>").unwrap();
+        assert_eq!(m.display_name.as_deref(), Some("Jane \"JD\" Doe"));
+    }
+
+    #[test]
+    fn mailbox_bare_and_angle_only() {
+        let bare = Email::parse_mailbox("jane@example.com").unwrap();
+        assert_eq!(bare.display_name, None);
+        let angle = Email::parse_mailbox("<jane@example.com>").unwrap();
+        assert_eq!(angle.display_name, None);
+        assert_eq!(angle.email.as_str(), "jane@example.com");
+    }
+
+    #[test]
+    fn mailbox_rejects_comma() {
+        assert!(matches!(
+            Email::parse_mailbox("a@b.com, c@d.com"),
+            Err(AclError::InvalidMailbox)
+        ));
+    }
+
+    #[test]
+    fn handle_charset_and_length() {
+        assert!(Handle::parse("sigma_01").is_ok());
+        assert!(matches!(Handle::parse("ab"), Err(AclError::InvalidHandle)));
+        assert!(matches!(Handle::parse("no spaces"), Err(AclError::InvalidHandle)));
+    }
+
+    #[test]
+    fn handle_serde_validates() {
+        let h: Handle = serde_json::from_str("\"sigma-01\"").unwrap();
+        assert_eq!(h.as_str(), "sigma-01");
+        assert_eq!(serde_json::to_string(&h).unwrap(), "\"sigma-01\"");
+        assert!(serde_json::from_str::<Handle>("\"x\"").is_err());
+    }
+
+    #[test]
+    fn value_object_round_trips_raw() {
+        let u = Username::new("sigma").unwrap();
+        assert_eq!(ValueObject::as_raw(&u), "sigma");
+        let e = Email::parse("sigma@example.com").unwrap();
+        assert_eq!(e.as_raw().as_str(), "sigma@example.com");
+    }
+
+    #[test]
+    fn loose_time_detects_magnitude() {
+        let p = LooseTimeParser::default();
+        let secs = p.parse(LooseTime::UnixSecs(1_700_000_000)).unwrap();
+        let millis = p.parse(LooseTime::UnixSecs(1_700_000_000_000)).unwrap();
+        assert_eq!(secs, millis);
+        let micros = p.parse(LooseTime::UnixSecs(1_700_000_000_000_000)).unwrap();
+        assert_eq!(secs, micros);
+    }
+
+    #[test]
+    fn loose_time_zoneless_datetime_assumes_utc() {
+        let p = LooseTimeParser::default();
+        let with_zone = p.parse(LooseTime::Rfc3339("2024-12-25 12:34:56 UTC".into())).unwrap();
+        let without_zone = p.parse(LooseTime::Rfc3339("2024-12-25 12:34:56".into())).unwrap();
+        assert_eq!(with_zone, without_zone);
+        assert_eq!(without_zone.offset(), time::UtcOffset::UTC);
+        assert_eq!(without_zone.unix_timestamp(), 1735130096);
+    }
+
+    #[test]
+    fn loose_time_date_only_is_midnight_utc() {
+        let p = LooseTimeParser::default();
+        let dt = p.parse(LooseTime::Rfc3339("2024-12-25".into())).unwrap();
+        assert_eq!(dt.hour(), 0);
+        assert_eq!(dt.minute(), 0);
+        assert_eq!(dt.offset(), time::UtcOffset::UTC);
+    }
+
+    #[test]
+    fn loose_time_rfc2822() {
+        let p = LooseTimeParser::default();
+        let dt = p.parse(LooseTime::Rfc3339("Wed, 25 Dec 2024 12:34:56 +0000".into())).unwrap();
+        assert_eq!(dt.unix_timestamp(), 1735130096);
+    }
+
+    #[test]
+    fn mailto_parse_full() {
+        let m = Mailto::parse("mailto:a@b.com,c@d.com?subject=Hi&cc=e@f.com&body=Hello%20there").unwrap();
+        assert_eq!(m.to.len(), 2);
+        assert_eq!(m.cc.len(), 1);
+        assert_eq!(m.subject.as_deref(), Some("Hi"));
+        assert_eq!(m.body.as_deref(), Some("Hello there"));
+    }
+
+    #[test]
+    fn mailto_plus_addressing_is_literal() {
+        let m = Mailto::parse("mailto:user+tag@example.com?cc=ann+news@f.com").unwrap();
+        assert_eq!(m.to[0].as_str(), "user+tag@example.com");
+        assert_eq!(m.cc[0].as_str(), "ann+news@f.com");
+    }
+
+    #[test]
+    fn mailto_empty_path_with_to_header() {
+        let m = Mailto::parse("mailto:?to=a@b.com").unwrap();
+        assert_eq!(m.to.len(), 1);
+        assert!(Mailto::parse("mailto:").is_err());
+    }
+
+    #[test]
+    fn mailto_unknown_header_policy() {
+        assert!(Mailto::parse("mailto:a@b.com?weird=1").is_ok());
+        assert!(matches!(
+            Mailto::parse_with("mailto:a@b.com?weird=1", UnknownHeaderPolicy::Reject),
+            Err(AclError::InvalidMailto)
+        ));
+    }
+
+    #[test]
+    fn mailto_bad_percent_escape() {
+        assert!(matches!(
+            Mailto::parse("mailto:a@b.com?subject=%zz"),
+            Err(AclError::InvalidMailto)
+        ));
+    }
+
+    #[test]
+    fn mailto_roundtrip() {
+        let m = Mailto::parse("mailto:a@b.com?subject=Hi%20there&cc=e@f.com").unwrap();
+        let again = Mailto::parse(&m.to_uri()).unwrap();
+        assert_eq!(m, again);
+    }
+
     #[test]
     fn discriminated_union_states() {
         let ok = TxnFetch::Ok { txns: vec!["t1".into()] };